@@ -1,17 +1,40 @@
 use wasm_bindgen::prelude::*;
+use wasm_bindgen::Clamped;
 use wasm_bindgen::JsCast;
 use wasm_bindgen::closure::Closure;
 use web_sys::{
-	window, 
-	HtmlCanvasElement, 
+	window,
+	HtmlCanvasElement,
 	CanvasRenderingContext2d,
 	HtmlButtonElement,
+	ImageData,
 	MouseEvent,
 	PointerEvent,
+	WheelEvent,
 };
 use std::cell::RefCell;
 use std::rc::Rc;
 
+/// Default simulation rate, in ticks per second, before `set_target_tps` is called.
+const DEFAULT_TARGET_TPS: f64 = 12.0;
+
+/// Upper bound on the accumulator so a long tab-switch/pause doesn't cause a
+/// burst of catch-up ticks (the "spiral of death"). The actual cap used at
+/// runtime is `max(MAX_ACCUMULATED_MS, step_interval_ms * MAX_CATCHUP_STEPS)`,
+/// so slow `target_tps` values (whose step interval exceeds this constant)
+/// still get to accumulate at least one tick's worth of time instead of
+/// being clamped into never reaching it.
+const MAX_ACCUMULATED_MS: f64 = 250.0;
+
+/// Number of step intervals the accumulator may hold when that exceeds
+/// `MAX_ACCUMULATED_MS`, which caps catch-up at this many ticks per frame.
+/// At faster `target_tps` the `MAX_ACCUMULATED_MS` cap applies instead, and
+/// more than this many ticks can run in one frame.
+const MAX_CATCHUP_STEPS: f64 = 4.0;
+
+/// Target cell size, in CSS pixels, before `device_pixel_ratio` scaling.
+const BASE_CELL_CSS: f64 = 8.0;
+
 #[wasm_bindgen]
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -20,11 +43,164 @@ pub enum Cell {
 	Alive = 1,
 }
 
+/// Default seed used until `Universe::reseed` is called.
+const DEFAULT_SEED: u64 = 0x2545_f491_4f6c_dd1d;
+
+/// A small, deterministic xorshift64* generator. Not cryptographically
+/// strong, but reproducible across runs for a given seed, which is what
+/// shareable starting states need.
+#[derive(Clone, Copy, Debug)]
+struct XorShiftRng {
+	state: u64,
+}
+
+impl XorShiftRng {
+	fn new(seed: u64) -> Self {
+		// xorshift64* has a fixed point at 0, so never seed with it.
+		Self { state: if seed == 0 { DEFAULT_SEED } else { seed } }
+	}
+
+	fn next_u64(&mut self) -> u64 {
+		let mut x = self.state;
+		x ^= x << 13;
+		x ^= x >> 7;
+		x ^= x << 17;
+		self.state = x;
+		x.wrapping_mul(0x2545_f491_4f6c_dd1d)
+	}
+
+	/// A uniform `f64` in `[0, 1)`, built from the top 53 bits of the stream.
+	fn next_f64(&mut self) -> f64 {
+		(self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+	}
+}
+
+/// Screen-space pan/zoom applied on top of the cell grid. `draw`/`draw_grid`
+/// are rendered in world (cell) space; the canvas is translated and scaled
+/// by `offset_x/offset_y`/`scale` around that content, mirroring Ruffle's
+/// `view_matrix`/`inverse_view_matrix` split.
+#[derive(Clone, Copy, Debug)]
+struct Camera {
+	scale: f64,
+	offset_x: f64,
+	offset_y: f64,
+}
+
+impl Camera {
+	fn new() -> Self {
+		Self { scale: 1.0, offset_x: 0.0, offset_y: 0.0 }
+	}
+
+	/// Inverse of the view transform: maps a canvas-space point back to
+	/// world (cell-grid) space.
+	fn screen_to_world(&self, x: f64, y: f64) -> (f64, f64) {
+		((x - self.offset_x) / self.scale, (y - self.offset_y) / self.scale)
+	}
+
+	/// Zooms by `factor`, keeping the world point under `(x, y)` fixed on screen.
+	fn zoom_toward(&mut self, x: f64, y: f64, factor: f64) {
+		self.offset_x = x - (x - self.offset_x) * factor;
+		self.offset_y = y - (y - self.offset_y) * factor;
+		self.scale *= factor;
+	}
+
+	fn pan(&mut self, dx: f64, dy: f64) {
+		self.offset_x += dx;
+		self.offset_y += dy;
+	}
+}
+
+/// Resolves a canvas-space point to the cell it falls in, or `None` when
+/// it lands outside the grid, routing the hit test through `camera`'s
+/// inverse transform instead of a hard-coded `x / cell_size`.
+fn cell_at(
+	camera: &Camera,
+	x: f64,
+	y: f64,
+	cell_size: f64,
+	width: u32,
+	height: u32,
+) -> Option<(u32, u32)> {
+	let (world_x, world_y) = camera.screen_to_world(x, y);
+	if world_x < 0.0 || world_y < 0.0 {
+		return None;
+	}
+
+	let col = (world_x / cell_size) as u32;
+	let row = (world_y / cell_size) as u32;
+	if col < width && row < height {
+		Some((row, col))
+	} else {
+		None
+	}
+}
+
+/// Fits a grid of square cells into a `container_width x container_height`
+/// CSS-pixel box, scaled by `device_pixel_ratio` so cells stay crisp on
+/// HiDPI displays. `base_cell_css` is the target cell size, in CSS pixels,
+/// before that scaling is applied. Returns `(columns, rows, cell_size_px)`,
+/// where `cell_size_px` is the device-pixel size each cell is drawn at.
+fn fit_grid(
+	container_width: f64,
+	container_height: f64,
+	device_pixel_ratio: f64,
+	base_cell_css: f64,
+) -> (u32, u32, f64) {
+	let cell_size_px = base_cell_css * device_pixel_ratio;
+	let columns = ((container_width * device_pixel_ratio) / cell_size_px).floor().max(1.0) as u32;
+	let rows = ((container_height * device_pixel_ratio) / cell_size_px).floor().max(1.0) as u32;
+	(columns, rows, cell_size_px)
+}
+
+/// Refits `universe` and both canvases to whatever CSS box `canvas` currently
+/// occupies, recomputing `cell_size` from its `devicePixelRatio`-scaled
+/// bounding box. Shared by the initial layout pass and the window-resize handler.
+fn apply_resize(
+	canvas: &HtmlCanvasElement,
+	ctx: &CanvasRenderingContext2d,
+	offscreen: &HtmlCanvasElement,
+	universe: &Rc<RefCell<Universe>>,
+	cell_size: &Rc<RefCell<f64>>,
+) {
+	let dpr = window().unwrap().device_pixel_ratio();
+	let rect = canvas
+		.dyn_ref::<web_sys::Element>()
+		.unwrap()
+		.get_bounding_client_rect();
+
+	let (columns, rows, cell_size_px) = fit_grid(rect.width(), rect.height(), dpr, BASE_CELL_CSS);
+	let width_px = (columns as f64 * cell_size_px).round() as u32;
+	let height_px = (rows as f64 * cell_size_px).round() as u32;
+
+	canvas.set_width(width_px);
+	canvas.set_height(height_px);
+	// Assigning width/height resets the 2D context to its defaults, which
+	// re-enables image smoothing — reassert it so the 1px/cell buffer still
+	// scales up crisply after this resize.
+	ctx.set_image_smoothing_enabled(false);
+	// The off-screen canvas holds one pixel per cell; it's scaled up to
+	// `width_px x height_px` at blit time instead of being allocated at that size.
+	offscreen.set_width(columns);
+	offscreen.set_height(rows);
+
+	universe.borrow_mut().resize(columns, rows);
+	*cell_size.borrow_mut() = cell_size_px;
+}
+
+/// What a pointer drag is currently doing: painting cells, or panning the camera.
+#[derive(Clone, Copy, Debug)]
+enum PointerMode {
+	Paint(Cell),
+	Pan { last_x: f64, last_y: f64 },
+}
+
 #[wasm_bindgen]
 pub struct Universe {
 	width: u32,
 	height: u32,
 	cells: Vec<Cell>,
+	target_tps: f64,
+	rng: XorShiftRng,
 }
 
 impl Universe {
@@ -46,29 +222,42 @@ impl Universe {
 				count += self.cells[idx] as u8;
 			}
 		}
-		count 
+		count
+	}
+
+	fn step_interval_ms(&self) -> f64 {
+		1000.0 / self.target_tps
 	}
 
-	pub fn draw(&self, ctx: &CanvasRenderingContext2d, cell_size: f64) {
+	/// Renders all cells in one `put_image_data` upload instead of issuing a
+	/// `fill_rect` per cell, which used to dominate frame time on large grids.
+	/// The buffer is one pixel per cell; the caller blits it onto a
+	/// full-size canvas via `draw_image` (with image smoothing disabled) to
+	/// get crisp, scaled cells without allocating a `cell_size`-scaled buffer.
+	pub fn draw(&self, ctx: &CanvasRenderingContext2d) -> Result<(), JsValue> {
+		let mut buffer = vec![0u8; (self.width * self.height * 4) as usize];
+
 		for row in 0..self.height {
 			for col in 0..self.width {
 				let idx = self.get_index(row, col);
+				let shade: u8 = if self.cells[idx] == Cell::Alive { 0 } else { 255 };
 
-				if self.cells[idx] == Cell::Alive {
-					ctx.set_fill_style(&JsValue::from_str("black"));
-				} else {
-					ctx.set_fill_style(&JsValue::from_str("white"));
-				}
-				ctx.fill_rect(
-					(col as f64) * cell_size,
-					(row as f64) * cell_size,
-					cell_size,
-					cell_size,
-				);
+				let offset = idx * 4;
+				buffer[offset] = shade;
+				buffer[offset + 1] = shade;
+				buffer[offset + 2] = shade;
+				buffer[offset + 3] = 255;
 			}
 		}
+
+		let image_data = ImageData::new_with_u8_clamped_array_and_sh(
+			Clamped(&mut buffer),
+			self.width,
+			self.height,
+		)?;
+		ctx.put_image_data(&image_data, 0.0, 0.0)
 	}
-	
+
 	pub fn draw_grid(&self, ctx: &CanvasRenderingContext2d, cell_size: f64) {
 		ctx.set_stroke_style(&JsValue::from_str("#444"));
 		ctx.set_line_width(1.0);
@@ -138,6 +327,54 @@ impl Universe {
 			width,
 			height,
 			cells,
+			target_tps: DEFAULT_TARGET_TPS,
+			rng: XorShiftRng::new(DEFAULT_SEED),
+		}
+	}
+
+	pub fn set_target_tps(&mut self, target_tps: f64) {
+		self.target_tps = target_tps.max(1.0);
+	}
+
+	/// Reallocates the grid to `new_width x new_height`, preserving the
+	/// overlapping top-left region and filling any newly exposed area with
+	/// `Cell::Dead`.
+	pub fn resize(&mut self, new_width: u32, new_height: u32) {
+		let mut next = vec![Cell::Dead; (new_width * new_height) as usize];
+
+		let copy_width = self.width.min(new_width);
+		let copy_height = self.height.min(new_height);
+
+		for row in 0..copy_height {
+			for col in 0..copy_width {
+				let old_idx = (row * self.width + col) as usize;
+				let new_idx = (row * new_width + col) as usize;
+				next[new_idx] = self.cells[old_idx];
+			}
+		}
+
+		self.width = new_width;
+		self.height = new_height;
+		self.cells = next;
+	}
+
+	/// Reseeds the internal PRNG. The same seed always produces the same
+	/// sequence of `randomize` calls, making boards reproducible/shareable.
+	pub fn reseed(&mut self, seed: u64) {
+		self.rng = XorShiftRng::new(seed);
+	}
+
+	/// Fills the grid, drawing a uniform `f64` per cell and setting it alive
+	/// when the draw falls below `density` (clamped to `[0, 1]`).
+	pub fn randomize(&mut self, density: f64) {
+		let density = density.clamp(0.0, 1.0);
+		let rng = &mut self.rng;
+		for cell in self.cells.iter_mut() {
+			*cell = if rng.next_f64() < density {
+				Cell::Alive
+			} else {
+				Cell::Dead
+			};
 		}
 	}
 }
@@ -153,23 +390,64 @@ pub fn start() -> Result<(), JsValue> {
 		.dyn_into::<HtmlCanvasElement>()?;
 
 	let universe = Universe::new();
-	let cell_size: f64 = 8.0;
+	let cell_size: Rc<RefCell<f64>> = Rc::new(RefCell::new(BASE_CELL_CSS));
 
-	canvas.set_width(universe.width * cell_size as u32);
-	canvas.set_height(universe.height * cell_size as u32);
+	canvas.set_width(universe.width * BASE_CELL_CSS as u32);
+	canvas.set_height(universe.height * BASE_CELL_CSS as u32);
 
-	let ctx = canvas 
+	let ctx = canvas
 		.get_context("2d")?
 		.unwrap()
 		.dyn_into::<CanvasRenderingContext2d>()?;
-	
+
 	let universe = Rc::new(RefCell::new(universe));
 	let ctx = Rc::new(ctx);
 
+	// `put_image_data` ignores the canvas transform, so the cell buffer is
+	// rendered into an off-screen canvas at 1px/cell and then blitted onto
+	// the visible canvas with `draw_image`, which both the scale-up and the
+	// pan/zoom transform apply to.
+	let offscreen = document
+		.create_element("canvas")?
+		.dyn_into::<HtmlCanvasElement>()?;
+	let (universe_width, universe_height) = {
+		let u = universe.borrow();
+		(u.width, u.height)
+	};
+	offscreen.set_width(universe_width);
+	offscreen.set_height(universe_height);
+	let offscreen_ctx = offscreen
+		.get_context("2d")?
+		.unwrap()
+		.dyn_into::<CanvasRenderingContext2d>()?;
+	let offscreen = Rc::new(offscreen);
+	let offscreen_ctx = Rc::new(offscreen_ctx);
+
+	// Fit the grid to whatever space the page actually gives the canvas,
+	// then refit it whenever the window is resized. A change to the canvas's
+	// container that doesn't resize the window isn't picked up.
+	apply_resize(&canvas, &ctx, &offscreen, &universe, &cell_size);
+
+	let canvas_for_resize = canvas.clone();
+	let ctx_for_resize = ctx.clone();
+	let offscreen_for_resize = offscreen.clone();
+	let universe_for_resize = universe.clone();
+	let cell_size_for_resize = cell_size.clone();
+	let on_resize = Closure::wrap(Box::new(move |_e: web_sys::Event| {
+		apply_resize(
+			&canvas_for_resize,
+			&ctx_for_resize,
+			&offscreen_for_resize,
+			&universe_for_resize,
+			&cell_size_for_resize,
+		);
+	}) as Box<dyn FnMut(_)>);
+	window().unwrap().set_onresize(Some(on_resize.as_ref().unchecked_ref()));
+	on_resize.forget();
+
 	let uni_for_click = universe.clone();
 // 	let canvas_for_click = canvas.clone();
-	let cell_size_click = cell_size;
-// 
+//
 // 	let on_canvas_click = Closure::wrap(Box::new(move |event: web_sys::MouseEvent| {
 // 		let rect = canvas_for_click
 // 			.dyn_ref::<web_sys::Element>()
@@ -198,8 +476,14 @@ pub fn start() -> Result<(), JsValue> {
 	let canvas_for_up    = canvas.clone();
 
 
-	
-	let drag_mode: Rc<RefCell<Option<Cell>>> = Rc::new(RefCell::new(None));
+
+	let drag_mode: Rc<RefCell<Option<PointerMode>>> = Rc::new(RefCell::new(None));
+	// Last pointer position in canvas-pixel space. The hovered cell is resolved
+	// from this fresh every RAF frame rather than cached, so it can't lag or
+	// stick when a wheel-zoom or window resize moves the grid under the pointer
+	// without a pointermove event.
+	let pointer_pos: Rc<RefCell<Option<(f64, f64)>>> = Rc::new(RefCell::new(None));
+	let camera: Rc<RefCell<Camera>> = Rc::new(RefCell::new(Camera::new()));
 
 	// let uni_for_ptr = universe.clone();
 	// let canvas_for_ptr = canvas.clone();
@@ -207,12 +491,10 @@ pub fn start() -> Result<(), JsValue> {
 	// let cell_size_ptr = cell_size;
 
 	let drag_for_down = drag_mode.clone();
-	
-	let on_pointer_down = Closure::wrap(Box::new(move |e: PointerEvent| {
-	
-		let paint_to = if e.button() == 2 { Cell::Dead } else { Cell::Alive };
-		*drag_for_down.borrow_mut() = Some(paint_to);
+	let camera_for_down = camera.clone();
+	let cell_size_for_down = cell_size.clone();
 
+	let on_pointer_down = Closure::wrap(Box::new(move |e: PointerEvent| {
 		canvas_for_down.set_pointer_capture(e.pointer_id()).ok();
 
 		let rect = canvas_for_down
@@ -221,13 +503,25 @@ pub fn start() -> Result<(), JsValue> {
 
 		let sx = canvas_for_down.width() as f64 / rect.width();
 		let sy = canvas_for_down.height() as f64 / rect.height();
-		
+
 		let x = (e.client_x() as f64 - rect.left()) * sx;
 		let y = (e.client_y() as f64 - rect.top()) * sy;
-		let col = (x / cell_size_click).floor() as u32;
-		let row = (y / cell_size_click).floor() as u32;
 
-		if row < uni_for_click.borrow().height && col < uni_for_click.borrow().width {
+		// Middle-button or modifier-drag pans; left/right paint as before.
+		if e.button() == 1 || e.alt_key() {
+			*drag_for_down.borrow_mut() = Some(PointerMode::Pan { last_x: x, last_y: y });
+			return;
+		}
+
+		let paint_to = if e.button() == 2 { Cell::Dead } else { Cell::Alive };
+		*drag_for_down.borrow_mut() = Some(PointerMode::Paint(paint_to));
+
+		let (width, height) = {
+			let u = uni_for_click.borrow();
+			(u.width, u.height)
+		};
+		let cell_size_val = *cell_size_for_down.borrow();
+		if let Some((row, col)) = cell_at(&camera_for_down.borrow(), x, y, cell_size_val, width, height) {
 			let mut u = uni_for_click.borrow_mut();
 			let idx = u.get_index(row, col);
 			u.cells[idx] = paint_to;
@@ -237,41 +531,63 @@ pub fn start() -> Result<(), JsValue> {
 	let uni_for_move = universe.clone();
 	// let canvas_for_move = canvas.clone();
 	let drag_for_move = drag_mode.clone();
-	// let cell_size_move = cell_size;
+	let pointer_pos_for_move = pointer_pos.clone();
+	let camera_for_move = camera.clone();
+	let cell_size_for_move = cell_size.clone();
 
 	let on_pointer_move = Closure::wrap(Box::new(move |e: PointerEvent| {
-		if let Some(paint_to) = *drag_for_move.borrow() {
-			let rect = canvas_for_move
-				.dyn_ref::<web_sys::Element>().unwrap()
-				.get_bounding_client_rect();
-
-			let sx = canvas_for_move.width() as f64 / rect.width();
-			let sy = canvas_for_move.height() as f64 / rect.height();
-			
-			let x = (e.client_x() as f64 - rect.left()) * sx;
-			let y = (e.client_y() as f64 - rect.top()) * sy;
-			let col = (x / cell_size_click).floor() as u32;
-			let row = (y / cell_size_click).floor() as u32;
-
-			if row < uni_for_move.borrow().height && col < uni_for_move.borrow().width {
-				let mut u = uni_for_move.borrow_mut();
-				let idx = u.get_index(row, col);
-				u.cells[idx] = paint_to;
+		let rect = canvas_for_move
+			.dyn_ref::<web_sys::Element>().unwrap()
+			.get_bounding_client_rect();
+
+		let sx = canvas_for_move.width() as f64 / rect.width();
+		let sy = canvas_for_move.height() as f64 / rect.height();
+
+		let x = (e.client_x() as f64 - rect.left()) * sx;
+		let y = (e.client_y() as f64 - rect.top()) * sy;
+
+		let mut mode = drag_for_move.borrow_mut();
+		match *mode {
+			Some(PointerMode::Pan { last_x, last_y }) => {
+				camera_for_move.borrow_mut().pan(x - last_x, y - last_y);
+				*mode = Some(PointerMode::Pan { last_x: x, last_y: y });
+				*pointer_pos_for_move.borrow_mut() = Some((x, y));
+			}
+			Some(PointerMode::Paint(paint_to)) => {
+				*pointer_pos_for_move.borrow_mut() = Some((x, y));
+
+				let (width, height) = {
+					let u = uni_for_move.borrow();
+					(u.width, u.height)
+				};
+				let cell_size_val = *cell_size_for_move.borrow();
+				if let Some((row, col)) =
+					cell_at(&camera_for_move.borrow(), x, y, cell_size_val, width, height)
+				{
+					let mut u = uni_for_move.borrow_mut();
+					let idx = u.get_index(row, col);
+					u.cells[idx] = paint_to;
+				}
+			}
+			None => {
+				*pointer_pos_for_move.borrow_mut() = Some((x, y));
 			}
 		}
 	}) as Box<dyn FnMut(_)>);
 
 	let drag_for_up = drag_mode.clone();
 	// let canvas_for_up = canvas.clone();
-	
+
 	let on_pointer_up = Closure::wrap(Box::new(move |e: PointerEvent| {
 		*drag_for_up.borrow_mut() = None;
 		canvas_for_up.release_pointer_capture(e.pointer_id()).ok();
 	}) as Box<dyn FnMut(_)>);
 
 	let drag_for_leave = drag_mode.clone();
+	let pointer_pos_for_leave = pointer_pos.clone();
 	let on_pointer_leave = Closure::wrap(Box::new(move |_e: PointerEvent| {
 		*drag_for_leave.borrow_mut() = None;
+		*pointer_pos_for_leave.borrow_mut() = None;
 	}) as Box<dyn FnMut(_)>);
 
 	canvas.set_onpointerdown(Some(on_pointer_down.as_ref().unchecked_ref()));
@@ -283,6 +599,27 @@ pub fn start() -> Result<(), JsValue> {
 	on_pointer_up.forget();
 	on_pointer_leave.forget();
 
+	let canvas_for_wheel = canvas.clone();
+	let camera_for_wheel = camera.clone();
+	let on_wheel = Closure::wrap(Box::new(move |e: WheelEvent| {
+		e.prevent_default();
+
+		let rect = canvas_for_wheel
+			.dyn_ref::<web_sys::Element>().unwrap()
+			.get_bounding_client_rect();
+
+		let sx = canvas_for_wheel.width() as f64 / rect.width();
+		let sy = canvas_for_wheel.height() as f64 / rect.height();
+
+		let x = (e.client_x() as f64 - rect.left()) * sx;
+		let y = (e.client_y() as f64 - rect.top()) * sy;
+
+		let factor = if e.delta_y() < 0.0 { 1.1 } else { 1.0 / 1.1 };
+		camera_for_wheel.borrow_mut().zoom_toward(x, y, factor);
+	}) as Box<dyn FnMut(_)>);
+	canvas.set_onwheel(Some(on_wheel.as_ref().unchecked_ref()));
+	on_wheel.forget();
+
 	let canvas_for_ctx = canvas.clone();
 	let on_context_menu = Closure::wrap(Box::new(move |e: web_sys::Event| {
 		e.prevent_default();
@@ -310,40 +647,100 @@ pub fn start() -> Result<(), JsValue> {
 	on_click.forget();
 
 	
-	let raf_handle: Rc<RefCell<Option<Closure<dyn FnMut()>>>> = Rc::new(RefCell::new(None));
+	let raf_handle: Rc<RefCell<Option<Closure<dyn FnMut(f64)>>>> = Rc::new(RefCell::new(None));
 	let raf_handle_clone = raf_handle.clone();
-	
+
 	let uni_rc = universe.clone();
 	let ctx_rc = ctx.clone();
-	
-	let mut frame_count = 0;
+	let offscreen_rc = offscreen.clone();
+	let offscreen_ctx_rc = offscreen_ctx.clone();
+	let camera_rc = camera.clone();
+	let pointer_pos_rc = pointer_pos.clone();
+	let cell_size_rc = cell_size.clone();
+
 	let running_rc = running.clone();
-	
-	*raf_handle.borrow_mut() = Some(Closure::wrap(Box::new(move || {
-		frame_count += 1;
+	let last_timestamp: Rc<RefCell<Option<f64>>> = Rc::new(RefCell::new(None));
+	let accumulator: Rc<RefCell<f64>> = Rc::new(RefCell::new(0.0));
+
+	*raf_handle.borrow_mut() = Some(Closure::wrap(Box::new(move |timestamp: f64| {
+		let delta = match *last_timestamp.borrow() {
+			Some(last) => timestamp - last,
+			None => 0.0,
+		};
+		*last_timestamp.borrow_mut() = Some(timestamp);
+
+		if *running_rc.borrow() {
+			let mut acc = accumulator.borrow_mut();
+			let step = uni_rc.borrow().step_interval_ms();
+			*acc = (*acc + delta).min(MAX_ACCUMULATED_MS.max(step * MAX_CATCHUP_STEPS));
+
+			while *acc >= step {
+				uni_rc.borrow_mut().tick();
+				*acc -= step;
+			}
+		} else {
+			*accumulator.borrow_mut() = 0.0;
+		}
 
-		if *running_rc.borrow() && frame_count % 5 == 0 {
-			uni_rc.borrow_mut().tick();
-		}	
-		
 		let width = ctx_rc.canvas().unwrap().width() as f64;
 		let height = ctx_rc.canvas().unwrap().height() as f64;
 		ctx_rc.clear_rect(0.0, 0.0, width, height);
-		uni_rc.borrow().draw(&ctx_rc, cell_size);
 
-		uni_rc.borrow().draw(&ctx_rc, cell_size);
+		let cell_size = *cell_size_rc.borrow();
+		uni_rc.borrow().draw(&offscreen_ctx_rc).unwrap();
+
+		ctx_rc.save();
+		let cam = *camera_rc.borrow();
+		ctx_rc.translate(cam.offset_x, cam.offset_y).unwrap();
+		ctx_rc.scale(cam.scale, cam.scale).unwrap();
+
+		let (grid_width, grid_height) = {
+			let u = uni_rc.borrow();
+			(u.width, u.height)
+		};
+		ctx_rc
+			.draw_image_with_html_canvas_element_and_dw_and_dh(
+				&offscreen_rc,
+				0.0,
+				0.0,
+				grid_width as f64 * cell_size,
+				grid_height as f64 * cell_size,
+			)
+			.unwrap();
 		uni_rc.borrow().draw_grid(&ctx_rc, cell_size);
-		
+
+		// Resolved from the last known pointer position every frame (never
+		// cached as a row/col), so a wheel-zoom or window resize that moves
+		// the grid under a stationary pointer can't leave the highlight stuck
+		// on a now-wrong cell.
+		if let Some((px, py)) = *pointer_pos_rc.borrow() {
+			let (width, height) = {
+				let u = uni_rc.borrow();
+				(u.width, u.height)
+			};
+			if let Some((row, col)) = cell_at(&camera_rc.borrow(), px, py, cell_size, width, height) {
+				ctx_rc.set_fill_style(&JsValue::from_str("rgba(255, 80, 0, 0.35)"));
+				ctx_rc.fill_rect(
+					(col as f64) * cell_size,
+					(row as f64) * cell_size,
+					cell_size,
+					cell_size,
+				);
+			}
+		}
+
+		ctx_rc.restore();
+
 		let borrow = raf_handle_clone.borrow();
 		let cb = borrow
 			.as_ref()
 			.unwrap()
 			.as_ref()
 			.unchecked_ref();
-		
+
 		window().unwrap().request_animation_frame(cb).unwrap();
-		
-	}) as Box<dyn FnMut()>));
+
+	}) as Box<dyn FnMut(f64)>));
 
 
 